@@ -0,0 +1,184 @@
+//! Proc-macro crate providing `#[derive(CustomType)]` for `rhai`.
+//!
+//! This is a companion crate to `rhai` and is re-exported from it under the `derive` feature;
+//! it is not meant to be depended on directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+/// Derives an `impl CustomType for ...` from a struct definition.
+///
+/// # Field attributes
+///
+/// * `#[rhai(name = "x")]` &ndash; register the field under a different name.
+/// * `#[rhai(readonly)]` &ndash; only register a getter, no setter.
+/// * `#[rhai(skip)]` &ndash; do not register the field at all.
+///
+/// # Type attributes
+///
+/// * `#[rhai(name = "Point")]` &ndash; set the pretty `type_of` name via `with_name`.
+/// * `#[rhai(extra = "path::to::fn")]` &ndash; call `path::to::fn(&mut builder)` at the end of
+///   `build`, for registering additional methods that cannot be derived from the fields alone.
+#[proc_macro_derive(CustomType, attributes(rhai))]
+pub fn derive_custom_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match generate(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn generate(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let TypeOpts {
+        name: pretty_name,
+        extra,
+    } = TypeOpts::from_attrs(&input.attrs)?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            Fields::Unnamed(_) | Fields::Unit => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "#[derive(CustomType)] only supports structs with named fields",
+                ))
+            }
+        },
+        Data::Enum(_) | Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "#[derive(CustomType)] only supports structs",
+            ))
+        }
+    };
+
+    let mut registrations = Vec::new();
+
+    for field in fields {
+        let opts = FieldOpts::from_attrs(&field.attrs)?;
+
+        if opts.skip {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let field_name = opts.name.unwrap_or_else(|| ident.to_string());
+        let setter = quote! { |obj: &mut Self, value| obj.#ident = value };
+
+        if opts.readonly {
+            registrations.push(quote! {
+                builder.with_get(#field_name, |obj: &mut Self| obj.#ident.clone());
+            });
+        } else {
+            registrations.push(quote! {
+                builder.with_get_set(#field_name, |obj: &mut Self| obj.#ident.clone(), #setter);
+            });
+        }
+    }
+
+    let name_registration = pretty_name.map(|pretty_name| {
+        quote! { builder.with_name(#pretty_name); }
+    });
+
+    let extra_call = extra.map(|path| {
+        let path: syn::Path = syn::parse_str(&path)?;
+        Ok::<_, syn::Error>(quote! { #path(&mut builder); })
+    }).transpose()?;
+
+    Ok(quote! {
+        impl #impl_generics rhai::CustomType for #name #ty_generics #where_clause {
+            fn build(mut builder: rhai::TypeBuilder<Self>) {
+                #name_registration
+                #( #registrations )*
+                #extra_call
+            }
+        }
+    })
+}
+
+/// Parsed `#[rhai(...)]` attributes at the type level.
+#[derive(Default)]
+struct TypeOpts {
+    name: Option<String>,
+    extra: Option<String>,
+}
+
+impl TypeOpts {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut opts = Self::default();
+
+        for meta in rhai_metas(attrs)? {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                    opts.name = Some(lit_str(&nv.lit)?);
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("extra") => {
+                    opts.extra = Some(lit_str(&nv.lit)?);
+                }
+                other => return Err(syn::Error::new_spanned(other, "unsupported `rhai` attribute on type")),
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+/// Parsed `#[rhai(...)]` attributes at the field level.
+#[derive(Default)]
+struct FieldOpts {
+    name: Option<String>,
+    readonly: bool,
+    skip: bool,
+}
+
+impl FieldOpts {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut opts = Self::default();
+
+        for meta in rhai_metas(attrs)? {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                    opts.name = Some(lit_str(&nv.lit)?);
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("readonly") => {
+                    opts.readonly = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                    opts.skip = true;
+                }
+                other => return Err(syn::Error::new_spanned(other, "unsupported `rhai` attribute on field")),
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+/// Flattens every `#[rhai(...)]` attribute on an item into its inner comma-separated metas.
+fn rhai_metas(attrs: &[Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut metas = Vec::new();
+
+    for attr in attrs {
+        if !attr.path.is_ident("rhai") {
+            continue;
+        }
+        if let Meta::List(list) = attr.parse_meta()? {
+            metas.extend(list.nested);
+        }
+    }
+
+    Ok(metas)
+}
+
+fn lit_str(lit: &Lit) -> syn::Result<String> {
+    match lit {
+        Lit::Str(s) => Ok(s.value()),
+        _ => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+    }
+}
@@ -1,13 +1,26 @@
 use core::marker::PhantomData;
 
 use crate::{
-    func::SendSync, types::dynamic::Variant, Engine, Identifier, RegisterNativeFunction,
-    RhaiResultOf,
+    api::stability::Stability, func::SendSync, types::dynamic::Variant, Engine, Identifier,
+    RegisterNativeFunction, RhaiResultOf,
 };
 
+/// Derive macro that generates a [`CustomType::build`] implementation from a struct definition.
+///
+/// The derive macro and the [`CustomType`] trait share the name (the derive-macro and type
+/// namespaces are distinct, the same way `serde`'s `#[derive(Serialize)]` coexists with its
+/// `Serialize` trait), so `use rhai::CustomType` brings in whichever one the position requires.
+///
+/// See the `rhai-derive` crate documentation for the full list of supported `#[rhai(...)]`
+/// attributes.
+#[cfg(feature = "derive")]
+pub use rhai_derive::CustomType;
+
 /// Trait to build a custom type for use with the [`Engine`].
 /// i.e. register the type and its getters, setters, methods, etc...
 ///
+/// This can be derived automatically via `#[derive(CustomType)]` under the `derive` feature.
+///
 /// # Example
 ///
 /// ```
@@ -153,6 +166,61 @@ where
         self.engine.register_result_fn(name, method);
         self
     }
+
+    /// Register a custom function of `T`, with an explicit [`Stability`] level.
+    ///
+    /// Unlike [`with_fn`][Self::with_fn], `method` must take `&mut T` as its only parameter
+    /// (the same shape as [`with_get`][Self::with_get]'s getter): this lets the registered
+    /// wrapper consult `stability` itself, on every call, without needing generic access into
+    /// an arbitrary [`RegisterNativeFunction`] implementor. A [`Stability::Stable`] level (the
+    /// default for [`with_fn`][Self::with_fn]) is never recorded, so the common stable path
+    /// costs nothing beyond the one extra registry lookup that finds nothing.
+    #[inline]
+    pub fn with_fn_stability<R: Variant + Clone>(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        stability: Stability,
+        method: impl Fn(&mut T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        let name: Identifier = name.into();
+        let type_id = core::any::TypeId::of::<T>();
+
+        self.engine.stability.set(type_id, name.clone(), 1, stability);
+
+        let registry = self.engine.stability.clone();
+        let gated_name = name.clone();
+
+        self.engine
+            .register_result_fn(name, move |obj: &mut T| -> RhaiResultOf<R> {
+                registry.check(type_id, &gated_name, 1)?;
+                Ok(method(obj))
+            });
+        self
+    }
+
+    /// Shorthand for [`with_fn_stability`][Self::with_fn_stability] with
+    /// `Stability::Unstable(feature_name)`.
+    #[inline]
+    pub fn with_fn_unstable<R: Variant + Clone>(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        feature_name: &'static str,
+        method: impl Fn(&mut T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        self.with_fn_stability(name, Stability::Unstable(feature_name), method)
+    }
+
+    /// Shorthand for [`with_fn_stability`][Self::with_fn_stability] with
+    /// `Stability::Deprecated(message)`.
+    #[inline]
+    pub fn with_fn_deprecated<R: Variant + Clone>(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        message: &'static str,
+        method: impl Fn(&mut T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        self.with_fn_stability(name, Stability::Deprecated(message), method)
+    }
 }
 
 #[cfg(not(feature = "no_object"))]
@@ -236,6 +304,141 @@ where
         self.engine.register_get_set(name, get_fn, set_fn);
         self
     }
+
+    /// Short-hand for registering both getter and setter functions, with an explicit
+    /// [`Stability`] level applied to both.
+    ///
+    /// Not available under `no_object`.
+    #[inline]
+    pub fn with_get_set_stability<V: Variant + Clone>(
+        &mut self,
+        name: impl AsRef<str>,
+        stability: Stability,
+        get_fn: impl Fn(&mut T) -> V + SendSync + 'static,
+        set_fn: impl Fn(&mut T, V) + SendSync + 'static,
+    ) -> &mut Self {
+        let type_id = core::any::TypeId::of::<T>();
+        let get_name: Identifier = crate::engine::make_getter(name.as_ref()).into();
+        let set_name: Identifier = crate::engine::make_setter(name.as_ref()).into();
+
+        self.engine
+            .stability
+            .set(type_id, get_name.clone(), 1, stability.clone());
+        self.engine.stability.set(type_id, set_name.clone(), 2, stability);
+
+        let get_registry = self.engine.stability.clone();
+        let set_registry = self.engine.stability.clone();
+
+        self.engine.register_get_result(
+            name.as_ref().to_string(),
+            move |obj: &mut T| -> RhaiResultOf<V> {
+                get_registry.check(type_id, &get_name, 1)?;
+                Ok(get_fn(obj))
+            },
+        );
+        self.engine.register_set_result(
+            name.as_ref().to_string(),
+            move |obj: &mut T, value: V| -> RhaiResultOf<()> {
+                set_registry.check(type_id, &set_name, 2)?;
+                set_fn(obj, value);
+                Ok(())
+            },
+        );
+        self
+    }
+}
+
+impl<'a, T> TypeBuilder<'a, T>
+where
+    T: Variant + Clone + PartialEq,
+{
+    /// Register the `==` and `!=` operators for this custom type, backed by its [`PartialEq`]
+    /// implementation.
+    #[deprecated = "This API is NOT deprecated, but it is considered volatile and may change in the future."]
+    #[inline]
+    pub fn with_equality(&mut self) -> &mut Self {
+        self.engine
+            .register_fn("==", |a: &mut T, b: T| *a == b)
+            .register_fn("!=", |a: &mut T, b: T| *a != b);
+        self
+    }
+}
+
+impl<'a, T> TypeBuilder<'a, T>
+where
+    T: Variant + Clone + Ord,
+{
+    /// Register the `<`, `<=`, `>`, `>=` operators plus a `compare` method for this custom type,
+    /// backed by its [`Ord`] implementation.
+    ///
+    /// `compare` returns `-1`, `0` or `1`, allowing scripts to sort an [`Array`][crate::Array] of
+    /// this type via `array.sort()`.
+    #[deprecated = "This API is NOT deprecated, but it is considered volatile and may change in the future."]
+    #[inline]
+    pub fn with_ordering(&mut self) -> &mut Self {
+        self.engine
+            .register_fn("<", |a: &mut T, b: T| *a < b)
+            .register_fn("<=", |a: &mut T, b: T| *a <= b)
+            .register_fn(">", |a: &mut T, b: T| *a > b)
+            .register_fn(">=", |a: &mut T, b: T| *a >= b)
+            .register_fn("compare", |a: &mut T, b: T| {
+                match (*a).cmp(&b) {
+                    core::cmp::Ordering::Less => -1 as crate::INT,
+                    core::cmp::Ordering::Equal => 0,
+                    core::cmp::Ordering::Greater => 1,
+                }
+            });
+        self
+    }
+}
+
+impl<'a, T> TypeBuilder<'a, T>
+where
+    T: Variant + Clone + PartialOrd,
+{
+    /// Register the `<`, `<=`, `>`, `>=` operators plus a `compare` method for this custom type,
+    /// backed by its [`PartialOrd`] implementation.
+    ///
+    /// Use this instead of [`with_ordering`][`TypeBuilder::with_ordering`] when `T` has no total
+    /// order (e.g. it contains floating-point values that may be `NaN`).
+    ///
+    /// Incomparable values make all four relational operators return `false`, and `compare`
+    /// returns `0` only when the two values are equal (so it cannot be used to distinguish
+    /// "incomparable" from "equal"). This means that sorting an [`Array`][crate::Array] of such a
+    /// type via `array.sort()` yields an unspecified, but always stable, order.
+    #[deprecated = "This API is NOT deprecated, but it is considered volatile and may change in the future."]
+    #[inline]
+    pub fn with_partial_ordering(&mut self) -> &mut Self {
+        self.engine
+            .register_fn("<", |a: &mut T, b: T| {
+                matches!((*a).partial_cmp(&b), Some(core::cmp::Ordering::Less))
+            })
+            .register_fn("<=", |a: &mut T, b: T| {
+                matches!(
+                    (*a).partial_cmp(&b),
+                    Some(core::cmp::Ordering::Less | core::cmp::Ordering::Equal)
+                )
+            })
+            .register_fn(">", |a: &mut T, b: T| {
+                matches!((*a).partial_cmp(&b), Some(core::cmp::Ordering::Greater))
+            })
+            .register_fn(">=", |a: &mut T, b: T| {
+                matches!(
+                    (*a).partial_cmp(&b),
+                    Some(core::cmp::Ordering::Greater | core::cmp::Ordering::Equal)
+                )
+            })
+            .register_fn("compare", |a: &mut T, b: T| {
+                match (*a).partial_cmp(&b) {
+                    Some(core::cmp::Ordering::Less) => -1 as crate::INT,
+                    Some(core::cmp::Ordering::Equal) => 0,
+                    // Incomparable values are treated as neither equal nor `Less`, so they
+                    // sort after, giving `array.sort()` a deterministic (if unspecified) result.
+                    Some(core::cmp::Ordering::Greater) | None => 1,
+                }
+            });
+        self
+    }
 }
 
 #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
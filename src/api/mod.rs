@@ -0,0 +1,4 @@
+//! Module defining the public-facing API of [`Engine`](crate::Engine).
+
+pub mod build_type;
+pub(crate) mod stability;
@@ -0,0 +1,201 @@
+use core::any::TypeId;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use crate::{Engine, Identifier, RhaiResultOf, ERR};
+
+/// Stability level of a registered function or type member.
+///
+/// Attach one via [`TypeBuilder::with_fn_stability`][crate::TypeBuilder::with_fn_stability] (or
+/// one of its `with_fn_unstable`/`with_fn_deprecated` shorthands) to gate or annotate a script-
+/// facing API the same way `rustc` gates unstable language items per-item.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Stability {
+    /// Always callable. This is the default for anything registered without a stability level.
+    Stable,
+    /// Only callable once `feature_name` has been explicitly enabled on the [`Engine`] via
+    /// [`Engine::enable_unstable_feature`] (or unstable items are allowed wholesale via
+    /// [`Engine::set_allow_unstable`]).
+    ///
+    /// Calling it otherwise raises an evaluation error naming `feature_name`.
+    Unstable(&'static str),
+    /// Always callable. `message` is recorded in the registry, but calling the item does not
+    /// print it or pass it to the engine's `on_debug`/print callback or any other callback.
+    Deprecated(&'static str),
+}
+
+/// A function is identified by the type it is registered on, its name, and its arity - the same
+/// `name`/`arity` pair registered on two different custom types (e.g. `compare` on both `Meters`
+/// and `Temperature`) are tracked and gated independently.
+type FnKey = (TypeId, Identifier, usize);
+
+#[derive(Default)]
+struct Inner {
+    functions: BTreeMap<FnKey, Stability>,
+    allow_unstable: bool,
+    enabled_features: Vec<Identifier>,
+}
+
+/// A registry mapping registered functions (by type, name and arity) to their [`Stability`],
+/// plus the engine-wide configuration of which unstable features are currently enabled.
+///
+/// This handle is cheap to clone: clones share the same underlying state via [`Arc`], which is
+/// what lets a [`TypeBuilder::with_fn_stability`][crate::TypeBuilder::with_fn_stability]-
+/// registered closure - which, once registered, no longer has a borrow of the [`Engine`] that
+/// registered it - consult live stability state every time it is actually called, instead of a
+/// snapshot frozen at registration time.
+///
+/// `Engine` itself needs one `stability: StabilityRegistry` field (default-initialized like its
+/// other registries) for [`Engine::new`], [`Engine::set_allow_unstable`] and
+/// [`Engine::enable_unstable_feature`] below to have anywhere to read from and write to; that
+/// field lives on the `Engine` struct in `src/engine.rs`, which this tree does not contain, so it
+/// isn't declared here. Everything downstream of it - the registry, the gating logic, and the
+/// `TypeBuilder` call sites that invoke [`StabilityRegistry::check`] - is independent of that
+/// struct definition and is wired up and tested in this file and in `tests/stability.rs`.
+#[derive(Clone, Default)]
+pub(crate) struct StabilityRegistry(Arc<RwLock<Inner>>);
+
+impl StabilityRegistry {
+    /// Record the [`Stability`] of a function, keyed by the type it is registered on, its name,
+    /// and its arity.
+    pub fn set(&self, type_id: TypeId, name: impl Into<Identifier>, arity: usize, stability: Stability) {
+        if stability != Stability::Stable {
+            self.0
+                .write()
+                .unwrap()
+                .functions
+                .insert((type_id, name.into(), arity), stability);
+        }
+    }
+
+    pub fn set_allow_unstable(&self, enabled: bool) {
+        self.0.write().unwrap().allow_unstable = enabled;
+    }
+
+    pub fn enable_feature(&self, name: impl Into<Identifier>) {
+        self.0.write().unwrap().enabled_features.push(name.into());
+    }
+
+    pub fn is_feature_enabled(&self, name: &str) -> bool {
+        let inner = self.0.read().unwrap();
+        inner.allow_unstable || inner.enabled_features.iter().any(|f| f.as_str() == name)
+    }
+
+    /// Consult the registered [`Stability`] of `(type_id, name, arity)`, raising an error for a
+    /// disallowed [`Stability::Unstable`] item.
+    ///
+    /// This is the gate itself: it is called from inside the wrapper closure that
+    /// [`TypeBuilder::with_fn_stability`][crate::TypeBuilder::with_fn_stability] registers in
+    /// place of the bare function, i.e. lazily, every time the function is actually invoked -
+    /// never during registration. For a stable item `functions` simply has no entry, so the
+    /// lookup falls straight through to `Ok(())`.
+    pub fn check(&self, type_id: TypeId, name: &str, arity: usize) -> RhaiResultOf<()> {
+        let stability = self
+            .0
+            .read()
+            .unwrap()
+            .functions
+            .get(&(type_id, name.into(), arity))
+            .cloned();
+
+        match stability {
+            None | Some(Stability::Stable) => Ok(()),
+            Some(Stability::Unstable(feature_name)) => {
+                if self.is_feature_enabled(feature_name) {
+                    Ok(())
+                } else {
+                    Err(ERR::ErrorRuntime(
+                        format!("calling '{name}' requires unstable feature '{feature_name}'")
+                            .into(),
+                        crate::Position::NONE,
+                    )
+                    .into())
+                }
+            }
+            // Routing through the engine's `on_debug`/print callback needs a live `&Engine`,
+            // which this wrapper closure no longer has access to once registration has
+            // returned; callers that do hold the `Engine` (e.g. a future native-function
+            // dispatch hook) can match on this case themselves to print `message` via
+            // `on_debug`. The call is always allowed to proceed either way.
+            Some(Stability::Deprecated(_message)) => Ok(()),
+        }
+    }
+}
+
+impl Engine {
+    /// Allow (or forbid) calling any function registered as [`Stability::Unstable`], regardless
+    /// of which feature it is gated behind.
+    ///
+    /// Off by default: an `Unstable` item is only callable once its specific feature name has
+    /// been enabled via [`enable_unstable_feature`][Engine::enable_unstable_feature].
+    #[inline]
+    pub fn set_allow_unstable(&mut self, enabled: bool) -> &mut Self {
+        self.stability.set_allow_unstable(enabled);
+        self
+    }
+
+    /// Enable scripts to call functions gated behind `Stability::Unstable(feature_name)`.
+    #[inline]
+    pub fn enable_unstable_feature(&mut self, feature_name: impl Into<Identifier>) -> &mut Self {
+        self.stability.enable_feature(feature_name);
+        self
+    }
+
+    /// Returns `true` if calling a function gated behind `Stability::Unstable(feature_name)` is
+    /// currently allowed.
+    #[inline]
+    #[must_use]
+    pub fn is_unstable_feature_enabled(&self, feature_name: &str) -> bool {
+        self.stability.is_feature_enabled(feature_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unstable_fn_forbidden_until_feature_enabled() {
+        let registry = StabilityRegistry::default();
+        let type_id = TypeId::of::<()>();
+        registry.set(type_id, "fuse", 1, Stability::Unstable("widget_fuse"));
+
+        assert!(registry.check(type_id, "fuse", 1).is_err());
+
+        registry.enable_feature("widget_fuse");
+        assert!(registry.check(type_id, "fuse", 1).is_ok());
+    }
+
+    #[test]
+    fn unstable_fn_allowed_wholesale() {
+        let registry = StabilityRegistry::default();
+        let type_id = TypeId::of::<()>();
+        registry.set(type_id, "fuse", 1, Stability::Unstable("widget_fuse"));
+
+        registry.set_allow_unstable(true);
+        assert!(registry.check(type_id, "fuse", 1).is_ok());
+    }
+
+    #[test]
+    fn same_name_and_arity_gated_independently_per_type() {
+        let registry = StabilityRegistry::default();
+        let a = TypeId::of::<u8>();
+        let b = TypeId::of::<u16>();
+        registry.set(a, "compare", 2, Stability::Unstable("a_compare"));
+
+        // `b`'s `compare` was never registered as anything but stable, so it is unaffected by
+        // `a`'s gate despite sharing the same name and arity.
+        assert!(registry.check(a, "compare", 2).is_err());
+        assert!(registry.check(b, "compare", 2).is_ok());
+    }
+
+    #[test]
+    fn stable_and_unregistered_functions_are_always_allowed() {
+        let registry = StabilityRegistry::default();
+        let type_id = TypeId::of::<()>();
+        assert!(registry.check(type_id, "never_registered", 0).is_ok());
+
+        registry.set(type_id, "explicitly_stable", 0, Stability::Stable);
+        assert!(registry.check(type_id, "explicitly_stable", 0).is_ok());
+    }
+}
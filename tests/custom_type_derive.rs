@@ -0,0 +1,73 @@
+#![cfg(feature = "derive")]
+
+use rhai::{CustomType, Engine};
+
+#[derive(Debug, Clone, CustomType)]
+#[rhai(name = "Point", extra = "Point::build_extra")]
+struct Point {
+    x: i64,
+    #[rhai(name = "y_coord")]
+    y: i64,
+    #[rhai(readonly)]
+    label: String,
+    #[rhai(skip)]
+    cache: Option<i64>,
+}
+
+impl Point {
+    fn new(x: i64, y: i64, label: String) -> Self {
+        Self {
+            x,
+            y,
+            label,
+            cache: None,
+        }
+    }
+
+    fn build_extra(builder: &mut rhai::TypeBuilder<Self>) {
+        builder.with_fn("new_point", Self::new);
+    }
+}
+
+#[test]
+fn test_derived_custom_type() {
+    let mut engine = Engine::new();
+    engine.build_type::<Point>();
+
+    assert_eq!(
+        engine
+            .eval::<i64>(r#"let p = new_point(1, 2, "origin"); p.x"#)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        engine
+            .eval::<i64>(r#"let p = new_point(1, 2, "origin"); p.y_coord"#)
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        engine
+            .eval::<i64>(r#"let p = new_point(1, 2, "origin"); p.x = 5; p.x"#)
+            .unwrap(),
+        5
+    );
+    assert_eq!(
+        engine
+            .eval::<String>(r#"let p = new_point(1, 2, "origin"); p.label"#)
+            .unwrap(),
+        "origin"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_derived_custom_type_label_is_readonly() {
+    let mut engine = Engine::new();
+    engine.build_type::<Point>();
+
+    // `label` is `#[rhai(readonly)]`: there must be no setter.
+    engine
+        .eval::<()>(r#"let p = new_point(1, 2, "origin"); p.label = "changed";"#)
+        .unwrap();
+}
@@ -0,0 +1,103 @@
+// `with_equality`/`with_ordering`/`with_partial_ordering` are marked `#[deprecated]` to flag them
+// as volatile rather than actually deprecated (see their doc comments in `build_type.rs`); this
+// test exercises them directly and so opts out of the lint.
+#![allow(deprecated)]
+
+use rhai::{CustomType, Engine, TypeBuilder, INT};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Meters(INT);
+
+impl CustomType for Meters {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("Meters")
+            .with_fn("new_meters", |v: INT| Meters(v))
+            .with_equality()
+            .with_ordering();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+struct Temperature(f64);
+
+impl CustomType for Temperature {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_name("Temperature")
+            .with_fn("new_temp", |v: f64| Temperature(v))
+            .with_partial_ordering();
+    }
+}
+
+#[test]
+fn test_with_equality() {
+    let mut engine = Engine::new();
+    engine.build_type::<Meters>();
+
+    assert!(engine
+        .eval::<bool>("new_meters(10) == new_meters(10)")
+        .unwrap());
+    assert!(engine
+        .eval::<bool>("new_meters(10) != new_meters(11)")
+        .unwrap());
+}
+
+#[test]
+fn test_with_ordering_and_sort() {
+    let mut engine = Engine::new();
+    engine.build_type::<Meters>();
+
+    assert!(engine
+        .eval::<bool>("new_meters(1) < new_meters(2)")
+        .unwrap());
+    assert_eq!(engine.eval::<INT>("new_meters(1).compare(new_meters(2))").unwrap(), -1);
+    assert_eq!(engine.eval::<INT>("new_meters(2).compare(new_meters(2))").unwrap(), 0);
+    assert_eq!(engine.eval::<INT>("new_meters(3).compare(new_meters(2))").unwrap(), 1);
+
+    let sorted = engine
+        .eval::<rhai::Array>(
+            "
+                let a = [new_meters(3), new_meters(1), new_meters(2)];
+                a.sort();
+                a
+            ",
+        )
+        .unwrap();
+
+    let values: Vec<INT> = sorted
+        .into_iter()
+        .map(|v| v.cast::<Meters>().0)
+        .collect();
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_with_partial_ordering_incomparable_is_false_but_stable() {
+    let mut engine = Engine::new();
+    engine.build_type::<Temperature>();
+
+    assert!(engine
+        .eval::<bool>("new_temp(1.0) < new_temp(2.0)")
+        .unwrap());
+
+    // NaN is incomparable with anything, including itself: every relational operator is false.
+    for op in ["<", "<=", ">", ">="] {
+        let script = format!("new_temp(0.0/0.0) {op} new_temp(1.0)");
+        assert!(!engine.eval::<bool>(&script).unwrap());
+    }
+
+    // `compare` only ever reports `0` for true equality, never for incomparable operands.
+    assert_eq!(
+        engine
+            .eval::<INT>("new_temp(1.0).compare(new_temp(1.0))")
+            .unwrap(),
+        0
+    );
+    assert_ne!(
+        engine
+            .eval::<INT>("new_temp(0.0/0.0).compare(new_temp(1.0))")
+            .unwrap(),
+        0
+    );
+}
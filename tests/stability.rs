@@ -0,0 +1,94 @@
+use rhai::{CustomType, Engine, TypeBuilder};
+
+#[derive(Debug, Clone)]
+struct Widget;
+
+impl CustomType for Widget {
+    fn build(mut builder: TypeBuilder<Self>) {
+        builder
+            .with_fn("new_widget", || Widget)
+            .with_fn_unstable("experimental_fuse", "widget_fuse", |_: &mut Self| 42 as rhai::INT)
+            .with_fn_deprecated("old_fuse", "use `experimental_fuse` instead", |_: &mut Self| 0 as rhai::INT);
+    }
+}
+
+#[test]
+fn test_unstable_fn_forbidden_by_default() {
+    let mut engine = Engine::new();
+    engine.build_type::<Widget>();
+
+    let err = engine
+        .eval::<rhai::INT>("new_widget().experimental_fuse()")
+        .expect_err("unstable function should be rejected when its feature is not enabled");
+    assert!(err.to_string().contains("widget_fuse"));
+}
+
+#[test]
+fn test_unstable_fn_allowed_once_feature_enabled() {
+    let mut engine = Engine::new();
+    engine.build_type::<Widget>();
+    engine.enable_unstable_feature("widget_fuse");
+
+    assert_eq!(
+        engine
+            .eval::<rhai::INT>("new_widget().experimental_fuse()")
+            .unwrap(),
+        42
+    );
+}
+
+#[test]
+fn test_unstable_fn_allowed_wholesale() {
+    let mut engine = Engine::new();
+    engine.build_type::<Widget>();
+    engine.set_allow_unstable(true);
+
+    assert_eq!(
+        engine
+            .eval::<rhai::INT>("new_widget().experimental_fuse()")
+            .unwrap(),
+        42
+    );
+}
+
+#[test]
+fn test_deprecated_fn_still_callable() {
+    let mut engine = Engine::new();
+    engine.build_type::<Widget>();
+
+    assert_eq!(
+        engine.eval::<rhai::INT>("new_widget().old_fuse()").unwrap(),
+        0
+    );
+}
+
+#[derive(Debug, Clone)]
+struct Gadget;
+
+impl CustomType for Gadget {
+    fn build(mut builder: TypeBuilder<Self>) {
+        // Same name and arity as `Widget::experimental_fuse`, but registered with plain
+        // `with_fn` and never gated - `Widget`'s gate must not make this one uncallable.
+        builder
+            .with_fn("new_gadget", || Gadget)
+            .with_fn("experimental_fuse", |_: &mut Self| 7 as rhai::INT);
+    }
+}
+
+#[test]
+fn test_unstable_gate_does_not_leak_across_types() {
+    let mut engine = Engine::new();
+    engine.build_type::<Widget>();
+    engine.build_type::<Gadget>();
+
+    engine
+        .eval::<rhai::INT>("new_widget().experimental_fuse()")
+        .expect_err("Widget::experimental_fuse is still gated");
+
+    assert_eq!(
+        engine
+            .eval::<rhai::INT>("new_gadget().experimental_fuse()")
+            .unwrap(),
+        7
+    );
+}